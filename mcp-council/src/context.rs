@@ -0,0 +1,122 @@
+//! Pluggable document loaders that inject external source material into the
+//! chairman prompt.
+//!
+//! A loader is a shell command template keyed by file extension or URL scheme,
+//! with a `$1` placeholder for the path/URL — e.g. `pdf: "pdftotext $1 -"`,
+//! `url: "curl -fsSL $1"`, `docx: "pandoc --to plain $1"`. `handle_finalize`
+//! passes a list of sources; each is run through its matching template, its
+//! stdout captured and truncated to a character budget, and the results are
+//! prepended to the prompt under a `## Reference Material` section so the
+//! Chairman can ground its synthesis in material the individual models never
+//! saw.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tokio::process::Command;
+
+/// Maps extensions/schemes to command templates and caps each source's size.
+pub struct ContextLoader {
+    loaders: BTreeMap<String, String>,
+    budget: usize,
+}
+
+impl Default for ContextLoader {
+    fn default() -> Self {
+        let mut loaders = BTreeMap::new();
+        loaders.insert("pdf".to_string(), "pdftotext $1 -".to_string());
+        loaders.insert("docx".to_string(), "pandoc --to plain $1".to_string());
+        loaders.insert("url".to_string(), "curl -fsSL $1".to_string());
+        loaders.insert("txt".to_string(), "cat $1".to_string());
+        loaders.insert("md".to_string(), "cat $1".to_string());
+        Self {
+            loaders,
+            budget: 8_000,
+        }
+    }
+}
+
+impl ContextLoader {
+    /// Build a loader from finalize params, overlaying any `context_loaders`
+    /// map and `context_budget` on the defaults.
+    pub fn from_params(params: &Value) -> Self {
+        let mut loader = ContextLoader::default();
+        if let Some(map) = params.get("context_loaders").and_then(|v| v.as_object()) {
+            for (key, value) in map {
+                if let Some(template) = value.as_str() {
+                    loader.loaders.insert(key.to_ascii_lowercase(), template.to_string());
+                }
+            }
+        }
+        if let Some(budget) = params.get("context_budget").and_then(|v| v.as_u64()) {
+            loader.budget = budget as usize;
+        }
+        loader
+    }
+
+    /// Pick the command template for a source by URL scheme or file extension.
+    fn template_for(&self, source: &str) -> Option<&String> {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            return self.loaders.get("url");
+        }
+        let ext = source.rsplit('.').next().unwrap_or("").to_ascii_lowercase();
+        self.loaders.get(&ext)
+    }
+
+    /// Load every source, returning `(source, extracted_text)` pairs. Sources
+    /// with no matching loader or a failing command are skipped with a log.
+    pub async fn load(&self, sources: &[String]) -> Vec<(String, String)> {
+        let mut loaded = Vec::new();
+        for source in sources {
+            match self.template_for(source) {
+                Some(template) => match run_template(template, source).await {
+                    Ok(text) => loaded.push((source.clone(), self.truncate(&text))),
+                    Err(e) => eprintln!("context: failed to load {}: {}", source, e),
+                },
+                None => eprintln!("context: no loader registered for {}", source),
+            }
+        }
+        loaded
+    }
+
+    fn truncate(&self, text: &str) -> String {
+        match text.char_indices().nth(self.budget) {
+            None => text.to_string(),
+            Some((end, _)) => format!("{}…[truncated]", &text[..end]),
+        }
+    }
+}
+
+/// Render loaded sources as a `## Reference Material` Markdown section, or an
+/// empty string when there is nothing to attach.
+pub fn reference_material(sources: &[(String, String)]) -> String {
+    if sources.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("## Reference Material\n");
+    for (source, text) in sources {
+        out.push_str(&format!("### {}\n{}\n\n", source, text));
+    }
+    out
+}
+
+/// Run a command template, substituting `$1` with the source path/URL, and
+/// return its stdout.
+async fn run_template(template: &str, source: &str) -> Result<String> {
+    let command = template.replace("$1", source);
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .output()
+        .await
+        .context(format!("Failed to run context loader: {}", command))?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "Context loader exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
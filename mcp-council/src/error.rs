@@ -0,0 +1,105 @@
+//! Typed council errors mapped to stable JSON-RPC error codes.
+//!
+//! Tool failures used to collapse to code `-32603` with a stringified message
+//! and no `data`, forcing clients to regex-match English prose. [`CouncilError`]
+//! classifies each failure into a named kind with a stable numeric code in the
+//! JSON-RPC server-error range (`-32000..=-32099`) and a structured `data`
+//! object carrying the relevant context, so clients can react programmatically.
+
+use serde_json::{json, Value};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum CouncilError {
+    /// The `.council/<title>` directory does not exist.
+    TitleDirNotFound { title: String, searched_path: String },
+    /// No Stage1 `*-answer.*` files were found for the title.
+    NoStage1Answers { title: String },
+    /// An answer file could not be parsed.
+    AnswerParseFailed { file: String, reason: String },
+    /// Every answer was removed by the `self_model` exclusion.
+    SelfExclusionEmptied { self_model: String },
+    /// The underlying LLM CLI invocation failed.
+    LlmCliFailed { engine: String, reason: String },
+    /// The original user query could not be located.
+    QueryNotFound { title: String },
+}
+
+impl CouncilError {
+    /// Stable numeric code for this variant.
+    pub fn code(&self) -> i32 {
+        match self {
+            CouncilError::TitleDirNotFound { .. } => -32001,
+            CouncilError::NoStage1Answers { .. } => -32002,
+            CouncilError::AnswerParseFailed { .. } => -32003,
+            CouncilError::SelfExclusionEmptied { .. } => -32004,
+            CouncilError::LlmCliFailed { .. } => -32005,
+            CouncilError::QueryNotFound { .. } => -32006,
+        }
+    }
+
+    /// Short machine-readable kind, echoed in `data.kind`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CouncilError::TitleDirNotFound { .. } => "TitleDirNotFound",
+            CouncilError::NoStage1Answers { .. } => "NoStage1Answers",
+            CouncilError::AnswerParseFailed { .. } => "AnswerParseFailed",
+            CouncilError::SelfExclusionEmptied { .. } => "SelfExclusionEmptied",
+            CouncilError::LlmCliFailed { .. } => "LlmCliFailed",
+            CouncilError::QueryNotFound { .. } => "QueryNotFound",
+        }
+    }
+
+    /// Structured context for the `data` member of the JSON-RPC error.
+    pub fn data(&self) -> Value {
+        let mut data = match self {
+            CouncilError::TitleDirNotFound { title, searched_path } => {
+                json!({ "title": title, "searched_path": searched_path })
+            }
+            CouncilError::NoStage1Answers { title } => json!({ "title": title }),
+            CouncilError::AnswerParseFailed { file, reason } => {
+                json!({ "file": file, "reason": reason })
+            }
+            CouncilError::SelfExclusionEmptied { self_model } => {
+                json!({ "self_model": self_model })
+            }
+            CouncilError::LlmCliFailed { engine, reason } => {
+                json!({ "engine": engine, "reason": reason })
+            }
+            CouncilError::QueryNotFound { title } => json!({ "title": title }),
+        };
+        data["kind"] = json!(self.kind());
+        data
+    }
+}
+
+impl fmt::Display for CouncilError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CouncilError::TitleDirNotFound { title, searched_path } => write!(
+                f,
+                "Directory not found for title '{}' (searched from: {})",
+                title, searched_path
+            ),
+            CouncilError::NoStage1Answers { title } => {
+                write!(f, "No Stage1 answer files found for {}", title)
+            }
+            CouncilError::AnswerParseFailed { file, reason } => {
+                write!(f, "Failed to parse answer file {}: {}", file, reason)
+            }
+            CouncilError::SelfExclusionEmptied { self_model } => write!(
+                f,
+                "No Stage1 answers available after excluding self_model '{}'",
+                self_model
+            ),
+            CouncilError::LlmCliFailed { engine, reason } => {
+                write!(f, "LLM CLI '{}' failed: {}", engine, reason)
+            }
+            CouncilError::QueryNotFound { title } => {
+                write!(f, "Could not locate the user query for {}", title)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CouncilError {}
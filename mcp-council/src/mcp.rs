@@ -1,7 +1,18 @@
 use anyhow::{Context, Result};
+use axum::extract::State;
+use axum::response::sse::{Event, Sse};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use futures::stream::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::CouncilError;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct McpRequest {
@@ -11,6 +22,16 @@ struct McpRequest {
     params: Option<Value>,
 }
 
+/// Transport selected on the command line. `stdio` is the default used by
+/// editor integrations; `http` exposes the same dispatch over axum so a
+/// long `council.peer_review`/`council.finalize` run can be driven by a
+/// plain HTTP client and stream progress over SSE.
+#[derive(Debug, Clone)]
+pub enum Transport {
+    Stdio,
+    Http { addr: String },
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct McpResponse {
     jsonrpc: String,
@@ -37,66 +58,110 @@ impl McpServer {
         Self
     }
 
+    /// Entry point used by `main`: dispatch to the transport chosen by the
+    /// `--transport` flag.
+    pub async fn run_transport(&mut self, transport: Transport) -> Result<()> {
+        match transport {
+            Transport::Stdio => self.run().await,
+            Transport::Http { addr } => self.run_http(&addr).await,
+        }
+    }
+
     pub async fn run(&mut self) -> Result<()> {
         let stdin = tokio::io::stdin();
         let mut reader = BufReader::new(stdin);
         let mut stdout = tokio::io::stdout();
 
-        let mut buffer = String::new();
-
         loop {
-            buffer.clear();
-            let bytes_read = reader.read_line(&mut buffer).await?;
-
-            if bytes_read == 0 {
-                break; // EOF
-            }
+            let (message, framed) = match read_message(&mut reader).await? {
+                Some(msg) => msg,
+                None => break, // EOF
+            };
 
-            let line = buffer.trim();
-            if line.is_empty() {
+            let trimmed = message.trim();
+            if trimmed.is_empty() {
                 continue;
             }
 
-            match self.handle_request(line).await {
-                Ok(Some(response)) => {
-                    let response_json = serde_json::to_string(&response)?;
-                    stdout.write_all(response_json.as_bytes()).await?;
+            if let Some(payload) = self.handle_message(trimmed).await {
+                if framed {
+                    // Mirror the client's framing for real MCP/LSP clients.
+                    let header = format!("Content-Length: {}\r\n\r\n", payload.len());
+                    stdout.write_all(header.as_bytes()).await?;
+                    stdout.write_all(payload.as_bytes()).await?;
+                } else {
+                    stdout.write_all(payload.as_bytes()).await?;
                     stdout.write_all(b"\n").await?;
-                    stdout.flush().await?;
                 }
-                Ok(None) => {
-                    // Notification (no id) or intentionally suppressed response
+                stdout.flush().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Dispatch one textual message, which may be a single JSON-RPC request or a
+    /// top-level batch array. Returns the serialized response (a single object,
+    /// or an array for a batch), or `None` when there is nothing to send — a
+    /// notification, an all-notification batch, or a malformed line (kept
+    /// lenient as before). For a batch, each contained request is dispatched
+    /// through [`handle_request`] and only the non-notification responses are
+    /// collected.
+    async fn handle_message(&self, text: &str) -> Option<String> {
+        match serde_json::from_str::<Value>(text) {
+            Ok(Value::Array(items)) => {
+                let mut responses = Vec::new();
+                for item in items {
+                    match serde_json::from_value::<McpRequest>(item) {
+                        Ok(request) => {
+                            if let Ok(Some(response)) = self.handle_request(request).await {
+                                responses.push(response);
+                            }
+                        }
+                        Err(e) => eprintln!("Skipping malformed batch entry: {}", e),
+                    }
                 }
+                if responses.is_empty() {
+                    // All notifications (or all malformed): suppress output entirely.
+                    None
+                } else {
+                    serde_json::to_string(&responses).ok()
+                }
+            }
+            Ok(_) => match self.handle_line(text).await {
+                Ok(Some(response)) => serde_json::to_string(&response).ok(),
+                Ok(None) => None,
                 Err(e) => {
-                    // For malformed input (e.g., non-JSON lines), log and skip without emitting a JSON response
                     eprintln!("Error handling request (ignored): {}", e);
+                    None
                 }
+            },
+            Err(e) => {
+                eprintln!("Error handling request (ignored): {}", e);
+                None
             }
         }
-
-        Ok(())
     }
 
-    async fn handle_request(&self, line: &str) -> Result<Option<McpResponse>> {
+    /// Parse one textual JSON-RPC message and dispatch it. Kept separate from
+    /// [`handle_request`] so the HTTP transport can dispatch an already-parsed
+    /// [`McpRequest`] without re-serializing.
+    async fn handle_line(&self, line: &str) -> Result<Option<McpResponse>> {
         let request: McpRequest = serde_json::from_str(line)
             .context("Failed to parse JSON-RPC request")?;
+        self.handle_request(request).await
+    }
 
-        let mut request_id = request.id.clone();
+    async fn handle_request(&self, request: McpRequest) -> Result<Option<McpResponse>> {
+        let request_id = request.id.clone();
+        // Per JSON-RPC 2.0 a notification is a request with no `id` member; a
+        // null id is treated the same. Any other id value (including an array
+        // or object) is a real request and MUST be answered.
         let is_notification = match request_id.as_ref() {
             None => true,
             Some(v) if v.is_null() => true,
-            Some(v) if v.is_boolean() => true,
-            Some(v) if v.is_array() => true,
-            Some(v) if v.is_object() => true,
             _ => false,
         };
-        if is_notification && request_id.is_some() {
-            eprintln!(
-                "Invalid JSON-RPC id (ignored, treated as notification): {:?}",
-                request_id
-            );
-            request_id = None;
-        }
         let response_id = if is_notification { None } else { request_id.clone() };
 
         let result = match request.method.as_str() {
@@ -149,9 +214,56 @@ impl McpServer {
                                         "description": "Conversation title/directory name"
                                     },
                                     "engine": {
+                                        "description": "Chairman LLM model/engine, or a list of engines to run concurrently (examples: sonnet, gemini, gpt, grok)",
+                                        "default": "claude",
+                                        "oneOf": [
+                                            { "type": "string" },
+                                            { "type": "array", "items": { "type": "string" } }
+                                        ]
+                                    },
+                                    "meta": {
+                                        "type": "boolean",
+                                        "description": "Run a second meta-synthesis pass reconciling all chairman outputs into a consensus",
+                                        "default": false
+                                    },
+                                    "arbiter": {
                                         "type": "string",
-                                        "description": "LLM model/engine (examples: sonnet, gemini, gpt, grok)",
-                                        "default": "claude"
+                                        "description": "Engine that runs the meta-synthesis pass (defaults to the first chairman engine to finish successfully)"
+                                    },
+                                    "format": {
+                                        "type": "string",
+                                        "enum": ["either", "markdown", "json", "html"],
+                                        "description": "Output format for the written artifact; either/unspecified defaults to Markdown",
+                                        "default": "either"
+                                    },
+                                    "context": {
+                                        "type": "array",
+                                        "items": { "type": "string" },
+                                        "description": "Paths/URLs of supporting documents to load into a Reference Material section"
+                                    },
+                                    "context_loaders": {
+                                        "type": "object",
+                                        "additionalProperties": { "type": "string" },
+                                        "description": "Override map from file extension / URL scheme to a shell command template with a $1 placeholder"
+                                    },
+                                    "context_budget": {
+                                        "type": "integer",
+                                        "description": "Maximum characters kept per loaded context source",
+                                        "default": 8000
+                                    }
+                                },
+                                "required": ["title"]
+                            }
+                        },
+                        {
+                            "name": "council.aggregate",
+                            "description": "Combine every peer review for a title into a consensus ranking (Borda count + Condorcet)",
+                            "inputSchema": {
+                                "type": "object",
+                                "properties": {
+                                    "title": {
+                                        "type": "string",
+                                        "description": "Conversation title/directory name"
                                     }
                                 },
                                 "required": ["title"]
@@ -187,11 +299,7 @@ impl McpServer {
                                     jsonrpc: "2.0".to_string(),
                                     id: response_id.clone(),
                                     result: None,
-                                    error: Some(McpError {
-                                        code: -32603,
-                                        message: format!("Peer review failed: {}", e),
-                                        data: None,
-                                    }),
+                                    error: Some(tool_error("Peer review failed", &e)),
                                 }));
                             }
                         }
@@ -215,11 +323,31 @@ impl McpServer {
                                     jsonrpc: "2.0".to_string(),
                                     id: response_id.clone(),
                                     result: None,
-                                    error: Some(McpError {
-                                        code: -32603,
-                                        message: format!("Finalize failed: {}", e),
-                                        data: None,
-                                    }),
+                                    error: Some(tool_error("Finalize failed", &e)),
+                                }));
+                            }
+                        }
+                    }
+                    "council.aggregate" => {
+                        match crate::tools::aggregate::handle_aggregate(arguments).await {
+                            Ok(result) => Some(json!({
+                                "content": [
+                                    {
+                                        "type": "text",
+                                        "text": serde_json::to_string(&result)?
+                                    }
+                                ]
+                            })),
+                            Err(e) => {
+                                if is_notification {
+                                    eprintln!("Aggregate failed for notification: {}", e);
+                                    return Ok(None);
+                                }
+                                return Ok(Some(McpResponse {
+                                    jsonrpc: "2.0".to_string(),
+                                    id: response_id.clone(),
+                                    result: None,
+                                    error: Some(tool_error("Aggregate failed", &e)),
                                 }));
                             }
                         }
@@ -269,5 +397,234 @@ impl McpServer {
             }))
         }
     }
+
+    /// Serve the same dispatch over HTTP. `POST /mcp` takes a single JSON-RPC
+    /// request and returns its [`McpResponse`]; `GET /sse` streams progress and
+    /// the final result as Server-Sent Events.
+    pub async fn run_http(&self, addr: &str) -> Result<()> {
+        let state = Arc::new(McpServer);
+        let app = Router::new()
+            .route("/mcp", post(http_mcp))
+            .route("/sse", get(http_sse))
+            .with_state(state);
+
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .context(format!("Failed to bind HTTP transport to {}", addr))?;
+        eprintln!("🌐 MCP HTTP transport listening on {}", addr);
+        axum::serve(listener, app)
+            .await
+            .context("HTTP transport terminated unexpectedly")?;
+        Ok(())
+    }
+}
+
+/// `POST /mcp`: dispatch a single JSON-RPC request and return the response.
+/// A notification (no response) is answered with `204 No Content`.
+async fn http_mcp(
+    State(server): State<Arc<McpServer>>,
+    Json(request): Json<McpRequest>,
+) -> impl IntoResponse {
+    match server.handle_request(request).await {
+        Ok(Some(response)) => Json(response).into_response(),
+        Ok(None) => axum::http::StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            let response = McpResponse {
+                jsonrpc: "2.0".to_string(),
+                id: None,
+                result: None,
+                error: Some(McpError {
+                    code: -32700,
+                    message: format!("Parse error: {}", e),
+                    data: None,
+                }),
+            };
+            Json(response).into_response()
+        }
+    }
+}
+
+/// `GET /sse`: run a request supplied as the `?request=<json>` query string and
+/// stream progress and the final result as Server-Sent Events, so clients can
+/// observe a long council run before it finishes.
+async fn http_sse(
+    State(server): State<Arc<McpServer>>,
+    axum::extract::Query(params): axum::extract::Query<SseParams>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<Event>();
+
+    tokio::spawn(async move {
+        let _ = tx.send(Event::default().event("progress").data("started"));
+
+        let request = match serde_json::from_str::<McpRequest>(&params.request) {
+            Ok(request) => request,
+            Err(e) => {
+                let payload = json!({ "error": format!("Parse error: {}", e) }).to_string();
+                let _ = tx.send(Event::default().event("result").data(payload));
+                let _ = tx.send(Event::default().event("done").data(""));
+                return;
+            }
+        };
+
+        // A finalize run writes milestones to an append-only JSONL log. Tail it
+        // while the dispatch runs so each milestone reaches the client as it
+        // happens instead of only the terminal result. Start fresh so we don't
+        // replay a previous run's events; other methods/backends have no log
+        // and simply stream `started` → `result` → `done`.
+        let event_log = finalize_event_log_path(&request);
+        if let Some(path) = &event_log {
+            let _ = std::fs::remove_file(path);
+        }
+
+        let dispatch = server.handle_request(request);
+        tokio::pin!(dispatch);
+
+        let mut offset = 0u64;
+        let payload = loop {
+            tokio::select! {
+                result = &mut dispatch => {
+                    break match result {
+                        Ok(Some(response)) => serde_json::to_string(&response)
+                            .unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e)),
+                        Ok(None) => "null".to_string(),
+                        Err(e) => json!({ "error": e.to_string() }).to_string(),
+                    };
+                }
+                _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {
+                    if let Some(path) = &event_log {
+                        offset = forward_events(path, offset, &tx);
+                    }
+                }
+            }
+        };
+
+        // Drain any milestones written between the last poll and completion.
+        if let Some(path) = &event_log {
+            let _ = forward_events(path, offset, &tx);
+        }
+
+        let _ = tx.send(Event::default().event("result").data(payload));
+        let _ = tx.send(Event::default().event("done").data(""));
+    });
+
+    let stream = tokio_stream::wrappers::UnboundedReceiverStream::new(rx).map(Ok);
+    Sse::new(stream)
+}
+
+/// Resolve the finalize event-log path for an SSE request, if it is a
+/// `tools/call` for `council.finalize` with a title and the active store has a
+/// filesystem home for that title. Other methods, tools, or non-filesystem
+/// backends return `None` (nothing to tail).
+fn finalize_event_log_path(request: &McpRequest) -> Option<PathBuf> {
+    if request.method != "tools/call" {
+        return None;
+    }
+    let params = request.params.as_ref()?;
+    if params.get("name")?.as_str()? != "council.finalize" {
+        return None;
+    }
+    let title = params.get("arguments")?.get("title")?.as_str()?;
+    let base = crate::store::resolve_store().ok()?.base_path(title)?;
+    Some(base.join("finalize-events.jsonl"))
+}
+
+/// Forward any complete JSONL lines appended to `path` beyond `offset` as SSE
+/// `progress` events, returning the new byte offset (end of the last complete
+/// line). A partial trailing line is left for the next poll. Best-effort: if
+/// the file can't be read yet, `offset` is returned unchanged.
+fn forward_events(
+    path: &Path,
+    offset: u64,
+    tx: &tokio::sync::mpsc::UnboundedSender<Event>,
+) -> u64 {
+    let Ok(bytes) = std::fs::read(path) else {
+        return offset;
+    };
+    if bytes.len() as u64 <= offset {
+        return offset;
+    }
+    let fresh = &bytes[offset as usize..];
+    let Some(last_nl) = fresh.iter().rposition(|b| *b == b'\n') else {
+        return offset;
+    };
+    let complete = &fresh[..=last_nl];
+    for line in complete.split(|b| *b == b'\n') {
+        if line.is_empty() {
+            continue;
+        }
+        let text = String::from_utf8_lossy(line).into_owned();
+        let _ = tx.send(Event::default().event("progress").data(text));
+    }
+    offset + complete.len() as u64
+}
+
+#[derive(Debug, Deserialize)]
+struct SseParams {
+    /// JSON-RPC request encoded as a query-string parameter.
+    request: String,
+}
+
+/// Read one message from `reader`, supporting both transports real clients use:
+/// `Content-Length:`-prefixed framing (LSP/MCP style) and the historical
+/// one-JSON-object-per-line mode. Returns the raw message body and whether it
+/// was framed, so the response can mirror the client's framing. `Ok(None)`
+/// signals EOF.
+async fn read_message<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<Option<(String, bool)>> {
+    let mut first = String::new();
+    if reader.read_line(&mut first).await? == 0 {
+        return Ok(None);
+    }
+
+    if !first.trim_start().to_ascii_lowercase().starts_with("content-length:") {
+        // Newline mode: the line itself is the message.
+        return Ok(Some((first.trim().to_string(), false)));
+    }
+
+    // Framed mode: parse headers until a blank line, then read exactly the
+    // declared number of bytes.
+    let mut content_length: Option<usize> = parse_content_length(&first);
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 {
+            break;
+        }
+        let header = header.trim_end_matches(['\r', '\n']);
+        if header.is_empty() {
+            break;
+        }
+        if let Some(len) = parse_content_length(header) {
+            content_length = Some(len);
+        }
+    }
+
+    let len = content_length.context("Content-Length header missing or invalid")?;
+    let mut buf = vec![0u8; len];
+    reader.read_exact(&mut buf).await?;
+    Ok(Some((String::from_utf8_lossy(&buf).into_owned(), true)))
+}
+
+fn parse_content_length(header: &str) -> Option<usize> {
+    let lower = header.to_ascii_lowercase();
+    let value = lower.trim_start().strip_prefix("content-length:")?;
+    value.trim().parse().ok()
+}
+
+/// Build a JSON-RPC error for a tool failure. A [`CouncilError`] carries a
+/// stable code and structured `data`; anything else falls back to the generic
+/// internal-error code with a `<prefix>: <message>` string.
+fn tool_error(prefix: &str, err: &anyhow::Error) -> McpError {
+    if let Some(council_err) = err.downcast_ref::<CouncilError>() {
+        McpError {
+            code: council_err.code(),
+            message: council_err.to_string(),
+            data: Some(council_err.data()),
+        }
+    } else {
+        McpError {
+            code: -32603,
+            message: format!("{}: {}", prefix, err),
+            data: None,
+        }
+    }
 }
 
@@ -0,0 +1,124 @@
+//! Borda-count aggregation of peer rankings into numeric council scores.
+//!
+//! Each reviewer produces an ordered list of the council's responses. Borda
+//! count turns those ordinal ballots into a cardinal scoreboard: for a council
+//! of `N` responses, a 1st-place pick earns `N-1` points, 2nd earns `N-2`, down
+//! to `0` for last. Points are summed across reviewers and ranked by total.
+
+use serde_json::{json, Value};
+
+/// One reviewer's ordered ballot. `ranking` lists model names best-first; the
+/// reviewer's own response (`reviewer_model`) is excluded from scoring so a
+/// model cannot vote for itself.
+pub struct Ballot {
+    pub reviewer: String,
+    pub reviewer_model: Option<String>,
+    pub ranking: Vec<String>,
+}
+
+/// A model's aggregated council score.
+pub struct Score {
+    pub model: String,
+    pub points: f64,
+}
+
+/// Aggregate ballots over the full roster of council models.
+///
+/// Edge cases, per the council rules:
+/// - a reviewer never scores its own response (self-voting is dropped);
+/// - models omitted from a ballot are treated as tied for last, splitting the
+///   remaining low-rank points evenly between them;
+/// - ties in the final total are broken deterministically by model name.
+pub fn borda_scores(ballots: &[Ballot], all_models: &[String]) -> Vec<Score> {
+    let n = all_models.len();
+    let mut totals: Vec<(String, f64)> =
+        all_models.iter().map(|m| (m.clone(), 0.0)).collect();
+
+    for ballot in ballots {
+        // Models this ballot is allowed to score: everyone except the reviewer.
+        let scorable: Vec<&String> = all_models
+            .iter()
+            .filter(|m| Some(m.as_str()) != ballot.reviewer_model.as_deref())
+            .collect();
+        let m = scorable.len();
+        if m == 0 {
+            continue;
+        }
+
+        // The explicit ranking, restricted to scorable models and de-duplicated.
+        let mut ranked: Vec<&String> = Vec::new();
+        for model in &ballot.ranking {
+            if scorable.iter().any(|s| **s == *model) && !ranked.iter().any(|r| **r == *model) {
+                ranked.push(model);
+            }
+        }
+        let k = ranked.len();
+
+        // Point value for rank i (0-based) in a council of N responses.
+        let value = |i: usize| (n.saturating_sub(1 + i)) as f64;
+
+        for (i, model) in ranked.iter().enumerate() {
+            add_points(&mut totals, model, value(i));
+        }
+
+        // Omitted scorable models share the remaining low-rank points evenly.
+        let omitted: Vec<&String> = scorable
+            .iter()
+            .copied()
+            .filter(|s| !ranked.iter().any(|r| **r == **s))
+            .collect();
+        if !omitted.is_empty() {
+            let remaining: f64 = (k..m).map(value).sum();
+            let share = remaining / omitted.len() as f64;
+            for model in omitted {
+                add_points(&mut totals, model, share);
+            }
+        }
+    }
+
+    let mut scores: Vec<Score> = totals
+        .into_iter()
+        .map(|(model, points)| Score { model, points })
+        .collect();
+    // Rank by total descending, breaking ties by model name.
+    scores.sort_by(|a, b| {
+        b.points
+            .partial_cmp(&a.points)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.model.cmp(&b.model))
+    });
+    scores
+}
+
+fn add_points(totals: &mut [(String, f64)], model: &str, points: f64) {
+    if let Some(entry) = totals.iter_mut().find(|(m, _)| m == model) {
+        entry.1 += points;
+    }
+}
+
+/// Render the scoreboard as a compact text block for the chairman prompt.
+pub fn scoreboard_text(scores: &[Score]) -> String {
+    scores
+        .iter()
+        .enumerate()
+        .map(|(idx, s)| format!("{}. {} — {:.2} points", idx + 1, s.model, s.points))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render the scoreboard as a Markdown `## Council Scores` table.
+pub fn scoreboard_markdown(scores: &[Score]) -> String {
+    let mut out = String::from("## Council Scores\n| Rank | Model | Borda Score |\n|---|---|---|\n");
+    for (idx, s) in scores.iter().enumerate() {
+        out.push_str(&format!("| {} | {} | {:.2} |\n", idx + 1, s.model, s.points));
+    }
+    out
+}
+
+/// Scoreboard as a JSON array for the tool response.
+pub fn scoreboard_json(scores: &[Score]) -> Value {
+    scores
+        .iter()
+        .map(|s| json!({ "model": s.model, "points": s.points }))
+        .collect()
+}
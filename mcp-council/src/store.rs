@@ -0,0 +1,584 @@
+//! Storage abstraction for the council stages.
+//!
+//! Every tool used to reach for `fs::read_dir`/`fs::read_to_string` against a
+//! `.council/<title>` directory directly, re-implementing the parent-directory
+//! search and the legacy filename migration on each call. [`CouncilStore`]
+//! hides that behind a trait — modelled on kittybox's `Storage` over its
+//! file/memory/postgres/redis backends — so the MCP tools work the same way
+//! whether backed by the filesystem ([`FilesystemStore`], the default), an
+//! in-memory map ([`MemoryStore`], for deterministic tests), or a single-file
+//! transactional database ([`SqliteStore`]).
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::error::CouncilError;
+
+/// A Stage1 answer, with its source name and parsed content (`model`,
+/// `response`, `raw`).
+#[derive(Debug, Clone)]
+pub struct AnswerRecord {
+    pub name: String,
+    pub content: Value,
+}
+
+/// A Stage2 review: the rendered markdown plus the machine-readable sidecar
+/// (`labels`, `ranking`) when one was persisted.
+#[derive(Debug, Clone)]
+pub struct ReviewRecord {
+    pub engine: String,
+    pub markdown: String,
+    pub sidecar: Option<Value>,
+}
+
+/// Backend-agnostic view over one council's stages.
+pub trait CouncilStore {
+    /// All Stage1 answers for a title, in a stable order.
+    fn list_answers(&self, title: &str) -> Result<Vec<AnswerRecord>>;
+    /// A single Stage1 answer by source name.
+    fn read_answer(&self, title: &str, name: &str) -> Result<Value>;
+    /// The original user question for a title; fails with
+    /// `CouncilError::QueryNotFound` when none can be located.
+    fn read_query(&self, title: &str) -> Result<String>;
+    /// Persist a Stage2 review and its sidecar, returning a backend-specific
+    /// location string for the markdown artifact.
+    fn write_review(
+        &self,
+        title: &str,
+        engine_for_file: &str,
+        markdown: &str,
+        sidecar: &Value,
+    ) -> Result<String>;
+    /// All Stage2 reviews for a title.
+    fn list_reviews(&self, title: &str) -> Result<Vec<ReviewRecord>>;
+    /// Persist a Stage3 final-answer artifact under `filename`, returning a
+    /// backend-specific location string.
+    fn write_final(&self, title: &str, filename: &str, content: &str) -> Result<String>;
+    /// Filesystem directory backing a title, when the backend has one. Used for
+    /// conveniences that are inherently file-based (e.g. a tail-able event log);
+    /// non-filesystem backends return `None`.
+    fn base_path(&self, _title: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Select a storage backend from the `COUNCIL_STORE` environment variable (set
+/// by the `--store` CLI flag): `memory`, `sqlite:<path>`, or the default
+/// filesystem store. Returned as a trait object so the tools are
+/// backend-agnostic.
+pub fn resolve_store() -> Result<Box<dyn CouncilStore>> {
+    match env::var("COUNCIL_STORE").ok().as_deref() {
+        Some("memory") => Ok(Box::new(MemoryStore::new())),
+        Some(spec) if spec.starts_with("sqlite:") => {
+            let path = spec.trim_start_matches("sqlite:");
+            Ok(Box::new(SqliteStore::open(Path::new(path))?))
+        }
+        _ => Ok(Box::new(FilesystemStore::discover()?)),
+    }
+}
+
+/// Parse one Stage1 answer file body into the canonical `{model, response, raw}`
+/// shape. Shared by every backend so JSON and markdown answers resolve
+/// identically regardless of where they are stored.
+pub fn parse_answer(name: &str, content: &str) -> Value {
+    let model_from_name = Path::new(name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(|s| s.replace("-answer", ""))
+        .unwrap_or_else(|| "unknown-model".to_string());
+
+    if let Ok(json_data) = serde_json::from_str::<Value>(content) {
+        let model = json_data
+            .get("model")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&model_from_name)
+            .to_string();
+        let response = format_response_content(&json_data);
+        return json!({
+            "model": model,
+            "response": response,
+            "raw": json_data
+        });
+    }
+
+    json!({
+        "model": model_from_name,
+        "response": content,
+        "raw": content
+    })
+}
+
+/// Extract the displayable response text from the several JSON shapes a model
+/// CLI might emit.
+pub fn format_response_content(content: &Value) -> String {
+    if let Some(text) = content.get("response").and_then(|v| v.as_str()) {
+        return text.to_string();
+    }
+    if let Some(text) = content.get("content").and_then(|v| v.as_str()) {
+        return text.to_string();
+    }
+    if let Some(text) = content.as_str() {
+        return text.to_string();
+    }
+    serde_json::to_string_pretty(content).unwrap_or_else(|_| "Invalid content".to_string())
+}
+
+fn is_answer_file(name: &str) -> bool {
+    name.contains("-answer.md")
+        || name.ends_with("answer.md")
+        || name.contains("-answer.json")
+        || name.ends_with("answer.json")
+}
+
+/// Filesystem-backed store rooted at a resolved `.council` directory. This is
+/// the default backend and preserves the historical on-disk layout.
+pub struct FilesystemStore {
+    root: PathBuf,
+}
+
+impl FilesystemStore {
+    /// Use an explicit `.council` directory.
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// The resolved `.council` directory backing this store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Resolve the `.council` directory by walking up from the current working
+    /// directory (up to 5 levels), falling back to `./.council`. Centralizes
+    /// the search that each tool used to inline.
+    pub fn discover() -> Result<Self> {
+        let current_dir = env::current_dir()?;
+        let mut dir = current_dir.clone();
+        for _ in 0..=5 {
+            let council_dir = dir.join(".council");
+            if council_dir.exists() {
+                return Ok(Self::new(council_dir));
+            }
+            match dir.parent() {
+                Some(parent) => dir = parent.to_path_buf(),
+                None => break,
+            }
+        }
+        Ok(Self::new(current_dir.join(".council")))
+    }
+
+    fn title_dir(&self, title: &str) -> Result<PathBuf> {
+        let base_dir = self.root.join(title);
+        if !base_dir.exists() {
+            return Err(CouncilError::TitleDirNotFound {
+                title: title.to_string(),
+                searched_path: self.root.display().to_string(),
+            }
+            .into());
+        }
+        Ok(base_dir)
+    }
+
+    /// Rename legacy review filenames (`peer-review-<engine>.md`) to the
+    /// current `peer-review-by-<engine>.md` scheme. A bare `peer-review.md`,
+    /// which names no engine, is not renamed. Kept out of the tool hot path —
+    /// it runs lazily before a review is written or listed.
+    fn migrate_review_names(&self, base_dir: &Path) -> Result<()> {
+        for entry in fs::read_dir(base_dir)? {
+            let Ok(entry) = entry else { continue };
+            let path = entry.path();
+            let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else {
+                continue;
+            };
+            if file_name.starts_with("peer-review-")
+                && file_name.ends_with(".md")
+                && !file_name.contains("peer-review-by-")
+            {
+                let engine_part = file_name
+                    .trim_start_matches("peer-review-")
+                    .trim_end_matches(".md");
+                if engine_part.is_empty() {
+                    continue;
+                }
+                let new_path = base_dir.join(format!("peer-review-by-{}.md", engine_part));
+                if !new_path.exists() {
+                    let _ = fs::rename(&path, &new_path).or_else(|_| {
+                        let legacy = fs::read_to_string(&path)?;
+                        fs::write(&new_path, legacy)
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CouncilStore for FilesystemStore {
+    fn list_answers(&self, title: &str) -> Result<Vec<AnswerRecord>> {
+        let base_dir = self.title_dir(title)?;
+        let mut records = Vec::new();
+        for entry in fs::read_dir(&base_dir)
+            .context(format!("Failed to read directory: {}", base_dir.display()))?
+        {
+            let entry = entry?;
+            let path = entry.path();
+            let Some(name) = path.file_name().map(|f| f.to_string_lossy().into_owned()) else {
+                continue;
+            };
+            if !is_answer_file(&name) {
+                continue;
+            }
+            let content = fs::read_to_string(&path).map_err(|e| CouncilError::AnswerParseFailed {
+                file: name.clone(),
+                reason: e.to_string(),
+            })?;
+            records.push(AnswerRecord {
+                content: parse_answer(&name, &content),
+                name,
+            });
+        }
+        records.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(records)
+    }
+
+    fn read_answer(&self, title: &str, name: &str) -> Result<Value> {
+        let base_dir = self.title_dir(title)?;
+        let path = base_dir.join(name);
+        let content = fs::read_to_string(&path).map_err(|e| CouncilError::AnswerParseFailed {
+            file: name.to_string(),
+            reason: e.to_string(),
+        })?;
+        Ok(parse_answer(name, &content))
+    }
+
+    fn read_query(&self, title: &str) -> Result<String> {
+        let base_dir = self.title_dir(title)?;
+        for file_name in ["query.txt", "user_query.txt", "question.txt", "input.txt"] {
+            let file_path = base_dir.join(file_name);
+            if file_path.exists() {
+                return Ok(fs::read_to_string(&file_path)?.trim().to_string());
+            }
+        }
+
+        // Fall back to a `query`/`user_query` field in a JSON answer.
+        for record in self.list_answers(title)? {
+            if let Some(raw) = record.content.get("raw") {
+                if let Some(query) = raw.get("query").or_else(|| raw.get("user_query")) {
+                    if let Some(query_str) = query.as_str() {
+                        return Ok(query_str.to_string());
+                    }
+                }
+            }
+        }
+        Err(CouncilError::QueryNotFound {
+            title: title.to_string(),
+        }
+        .into())
+    }
+
+    fn write_review(
+        &self,
+        title: &str,
+        engine_for_file: &str,
+        markdown: &str,
+        sidecar: &Value,
+    ) -> Result<String> {
+        let base_dir = self.title_dir(title)?;
+        self.migrate_review_names(&base_dir)?;
+
+        let review_md_path = base_dir.join(format!("peer-review-by-{}.md", engine_for_file));
+        fs::write(&review_md_path, markdown).context(format!(
+            "Failed to write review markdown file: {}",
+            review_md_path.display()
+        ))?;
+
+        let review_json_path = base_dir.join(format!("peer-review-by-{}.json", engine_for_file));
+        fs::write(&review_json_path, serde_json::to_string_pretty(sidecar)?).context(format!(
+            "Failed to write review sidecar file: {}",
+            review_json_path.display()
+        ))?;
+
+        Ok(review_md_path.to_string_lossy().into_owned())
+    }
+
+    fn list_reviews(&self, title: &str) -> Result<Vec<ReviewRecord>> {
+        let base_dir = self.title_dir(title)?;
+        self.migrate_review_names(&base_dir)?;
+
+        let mut records = Vec::new();
+        for entry in fs::read_dir(&base_dir)
+            .context(format!("Failed to read directory: {}", base_dir.display()))?
+        {
+            let entry = entry?;
+            let path = entry.path();
+            let Some(name) = path.file_name().map(|f| f.to_string_lossy().into_owned()) else {
+                continue;
+            };
+            if !(name.starts_with("peer-review-by-") && name.ends_with(".md")) {
+                continue;
+            }
+            let engine = name
+                .trim_start_matches("peer-review-by-")
+                .trim_end_matches(".md")
+                .to_string();
+            let markdown = fs::read_to_string(&path)
+                .context(format!("Failed to read file: {}", path.display()))?;
+            let sidecar = fs::read_to_string(base_dir.join(format!("peer-review-by-{}.json", engine)))
+                .ok()
+                .and_then(|c| serde_json::from_str::<Value>(&c).ok());
+            records.push(ReviewRecord {
+                engine,
+                markdown,
+                sidecar,
+            });
+        }
+        records.sort_by(|a, b| a.engine.cmp(&b.engine));
+        Ok(records)
+    }
+
+    fn write_final(&self, title: &str, filename: &str, content: &str) -> Result<String> {
+        let base_dir = self.title_dir(title)?;
+        let path = base_dir.join(filename);
+        fs::write(&path, content)
+            .context(format!("Failed to write final answer file: {}", path.display()))?;
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    fn base_path(&self, title: &str) -> Option<PathBuf> {
+        let base_dir = self.root.join(title);
+        base_dir.exists().then_some(base_dir)
+    }
+}
+
+/// In-memory store for deterministic tests. Answers and queries are seeded up
+/// front; reviews accumulate in a mutex-guarded map.
+#[derive(Default)]
+pub struct MemoryStore {
+    answers: BTreeMap<String, Vec<AnswerRecord>>,
+    queries: BTreeMap<String, String>,
+    reviews: Mutex<BTreeMap<String, Vec<ReviewRecord>>>,
+    finals: Mutex<BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed a Stage1 answer for a title. `body` may be JSON or plain text.
+    pub fn insert_answer(&mut self, title: &str, name: &str, body: &str) {
+        self.answers
+            .entry(title.to_string())
+            .or_default()
+            .push(AnswerRecord {
+                name: name.to_string(),
+                content: parse_answer(name, body),
+            });
+    }
+
+    /// Seed the user question for a title.
+    pub fn insert_query(&mut self, title: &str, query: &str) {
+        self.queries.insert(title.to_string(), query.to_string());
+    }
+}
+
+impl CouncilStore for MemoryStore {
+    fn list_answers(&self, title: &str) -> Result<Vec<AnswerRecord>> {
+        Ok(self.answers.get(title).cloned().unwrap_or_default())
+    }
+
+    fn read_answer(&self, title: &str, name: &str) -> Result<Value> {
+        self.answers
+            .get(title)
+            .and_then(|rs| rs.iter().find(|r| r.name == name))
+            .map(|r| r.content.clone())
+            .context(format!("Answer not found: {}/{}", title, name))
+    }
+
+    fn read_query(&self, title: &str) -> Result<String> {
+        self.queries.get(title).cloned().ok_or_else(|| {
+            CouncilError::QueryNotFound {
+                title: title.to_string(),
+            }
+            .into()
+        })
+    }
+
+    fn write_review(
+        &self,
+        title: &str,
+        engine_for_file: &str,
+        markdown: &str,
+        sidecar: &Value,
+    ) -> Result<String> {
+        let mut reviews = self.reviews.lock().unwrap();
+        let entry = reviews.entry(title.to_string()).or_default();
+        entry.retain(|r| r.engine != engine_for_file);
+        entry.push(ReviewRecord {
+            engine: engine_for_file.to_string(),
+            markdown: markdown.to_string(),
+            sidecar: Some(sidecar.clone()),
+        });
+        Ok(format!("memory://{}/peer-review-by-{}", title, engine_for_file))
+    }
+
+    fn list_reviews(&self, title: &str) -> Result<Vec<ReviewRecord>> {
+        let reviews = self.reviews.lock().unwrap();
+        let mut records = reviews.get(title).cloned().unwrap_or_default();
+        records.sort_by(|a, b| a.engine.cmp(&b.engine));
+        Ok(records)
+    }
+
+    fn write_final(&self, title: &str, filename: &str, content: &str) -> Result<String> {
+        self.finals
+            .lock()
+            .unwrap()
+            .entry(title.to_string())
+            .or_default()
+            .insert(filename.to_string(), content.to_string());
+        Ok(format!("memory://{}/{}", title, filename))
+    }
+}
+
+/// Single-file SQLite store. Keeps each stage in its own table so many reviews
+/// can be written concurrently inside a transaction rather than racing on
+/// individual files.
+pub struct SqliteStore {
+    conn: Mutex<rusqlite::Connection>,
+}
+
+impl SqliteStore {
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = rusqlite::Connection::open(path)
+            .context(format!("Failed to open SQLite store: {}", path.display()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS answers (
+                title TEXT NOT NULL,
+                name  TEXT NOT NULL,
+                body  TEXT NOT NULL,
+                PRIMARY KEY (title, name)
+            );
+            CREATE TABLE IF NOT EXISTS queries (
+                title TEXT PRIMARY KEY,
+                query TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS reviews (
+                title    TEXT NOT NULL,
+                engine   TEXT NOT NULL,
+                markdown TEXT NOT NULL,
+                sidecar  TEXT,
+                PRIMARY KEY (title, engine)
+            );
+            CREATE TABLE IF NOT EXISTS finals (
+                title    TEXT NOT NULL,
+                filename TEXT NOT NULL,
+                content  TEXT NOT NULL,
+                PRIMARY KEY (title, filename)
+            );",
+        )?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+}
+
+impl CouncilStore for SqliteStore {
+    fn list_answers(&self, title: &str) -> Result<Vec<AnswerRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt =
+            conn.prepare("SELECT name, body FROM answers WHERE title = ?1 ORDER BY name")?;
+        let rows = stmt.query_map([title], |row| {
+            let name: String = row.get(0)?;
+            let body: String = row.get(1)?;
+            Ok((name, body))
+        })?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (name, body) = row?;
+            records.push(AnswerRecord {
+                content: parse_answer(&name, &body),
+                name,
+            });
+        }
+        Ok(records)
+    }
+
+    fn read_answer(&self, title: &str, name: &str) -> Result<Value> {
+        let conn = self.conn.lock().unwrap();
+        let body: String = conn
+            .query_row(
+                "SELECT body FROM answers WHERE title = ?1 AND name = ?2",
+                [title, name],
+                |row| row.get(0),
+            )
+            .context(format!("Answer not found: {}/{}", title, name))?;
+        Ok(parse_answer(name, &body))
+    }
+
+    fn read_query(&self, title: &str) -> Result<String> {
+        let conn = self.conn.lock().unwrap();
+        let query: Option<String> = conn
+            .query_row("SELECT query FROM queries WHERE title = ?1", [title], |row| {
+                row.get(0)
+            })
+            .ok();
+        query.ok_or_else(|| {
+            CouncilError::QueryNotFound {
+                title: title.to_string(),
+            }
+            .into()
+        })
+    }
+
+    fn write_review(
+        &self,
+        title: &str,
+        engine_for_file: &str,
+        markdown: &str,
+        sidecar: &Value,
+    ) -> Result<String> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO reviews (title, engine, markdown, sidecar) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(title, engine) DO UPDATE SET markdown = excluded.markdown, sidecar = excluded.sidecar",
+            rusqlite::params![title, engine_for_file, markdown, serde_json::to_string(sidecar)?],
+        )?;
+        Ok(format!("sqlite://{}/peer-review-by-{}", title, engine_for_file))
+    }
+
+    fn list_reviews(&self, title: &str) -> Result<Vec<ReviewRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT engine, markdown, sidecar FROM reviews WHERE title = ?1 ORDER BY engine")?;
+        let rows = stmt.query_map([title], |row| {
+            let engine: String = row.get(0)?;
+            let markdown: String = row.get(1)?;
+            let sidecar: Option<String> = row.get(2)?;
+            Ok((engine, markdown, sidecar))
+        })?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (engine, markdown, sidecar) = row?;
+            records.push(ReviewRecord {
+                engine,
+                markdown,
+                sidecar: sidecar.and_then(|s| serde_json::from_str(&s).ok()),
+            });
+        }
+        Ok(records)
+    }
+
+    fn write_final(&self, title: &str, filename: &str, content: &str) -> Result<String> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO finals (title, filename, content) VALUES (?1, ?2, ?3)
+             ON CONFLICT(title, filename) DO UPDATE SET content = excluded.content",
+            rusqlite::params![title, filename, content],
+        )?;
+        Ok(format!("sqlite://{}/{}", title, filename))
+    }
+}
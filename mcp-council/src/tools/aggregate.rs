@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use crate::store::{resolve_store, CouncilStore};
+
+/// A single reviewer's ranking, already resolved from anonymized labels to the
+/// underlying model names (best first).
+struct ResolvedReview {
+    engine: String,
+    models: Vec<String>,
+}
+
+pub async fn handle_aggregate(params: Value) -> Result<Value> {
+    let title = params["title"]
+        .as_str()
+        .context("Missing required parameter: title")?;
+
+    let store = resolve_store()?;
+
+    // Load every review's sidecar and resolve each anonymized ranking back to
+    // model names.
+    let mut reviews = Vec::new();
+    for record in store.list_reviews(title)? {
+        let Some(sidecar) = record.sidecar else { continue };
+        let labels = sidecar.get("labels").and_then(|v| v.as_object());
+        let ranking = sidecar.get("ranking").and_then(|v| v.as_array());
+
+        let (labels, ranking) = match (labels, ranking) {
+            (Some(labels), Some(ranking)) => (labels, ranking),
+            _ => continue,
+        };
+
+        let models: Vec<String> = ranking
+            .iter()
+            .filter_map(|label| label.as_str())
+            .filter_map(|label| labels.get(label).and_then(|m| m.as_str()))
+            .map(|m| m.to_string())
+            .collect();
+
+        if !models.is_empty() {
+            reviews.push(ResolvedReview {
+                engine: record.engine,
+                models,
+            });
+        }
+    }
+
+    if reviews.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No resolvable peer reviews found for {}. Please run peer_review first.",
+            title
+        ));
+    }
+
+    // Borda count. For a ranking of n models, the top model earns n-1 points
+    // down to 0 for last. Because a reviewer may exclude its own response, not
+    // every review ranks every model, so normalize each model's total by the
+    // number of reviews that actually ranked it.
+    let mut points: BTreeMap<String, f64> = BTreeMap::new();
+    let mut ranked_count: BTreeMap<String, usize> = BTreeMap::new();
+    let mut first_place: BTreeMap<String, usize> = BTreeMap::new();
+
+    for review in &reviews {
+        let n = review.models.len();
+        for (idx, model) in review.models.iter().enumerate() {
+            *points.entry(model.clone()).or_insert(0.0) += (n - 1 - idx) as f64;
+            *ranked_count.entry(model.clone()).or_insert(0) += 1;
+            if idx == 0 {
+                *first_place.entry(model.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut scoreboard: Vec<(String, f64, usize, usize)> = points
+        .keys()
+        .map(|model| {
+            let total = points[model];
+            let ranked = ranked_count.get(model).copied().unwrap_or(0);
+            let normalized = if ranked > 0 { total / ranked as f64 } else { 0.0 };
+            let firsts = first_place.get(model).copied().unwrap_or(0);
+            (model.clone(), normalized, firsts, ranked)
+        })
+        .collect();
+
+    // Sort by normalized Borda score descending, breaking ties by first-place votes.
+    scoreboard.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.2.cmp(&a.2))
+            .then(a.0.cmp(&b.0))
+    });
+
+    // Pairwise Condorcet matrix: for each ordered pair (x, y), count reviews
+    // that rank x above y.
+    let models: Vec<String> = scoreboard.iter().map(|(m, _, _, _)| m.clone()).collect();
+    let mut pairwise: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+    for x in &models {
+        for y in &models {
+            if x == y {
+                continue;
+            }
+            let wins = reviews
+                .iter()
+                .filter(|r| {
+                    let xi = r.models.iter().position(|m| m == x);
+                    let yi = r.models.iter().position(|m| m == y);
+                    matches!((xi, yi), (Some(xi), Some(yi)) if xi < yi)
+                })
+                .count();
+            pairwise
+                .entry(x.clone())
+                .or_default()
+                .insert(y.clone(), wins);
+        }
+    }
+
+    // A Condorcet winner beats every other model in pairwise majority.
+    let condorcet_winner = models.iter().find(|x| {
+        models.iter().filter(|y| x != *y).all(|y| {
+            let xy = pairwise.get(*x).and_then(|m| m.get(y)).copied().unwrap_or(0);
+            let yx = pairwise.get(y).and_then(|m| m.get(*x)).copied().unwrap_or(0);
+            xy > yx
+        })
+    });
+
+    let scores_json: Vec<Value> = scoreboard
+        .iter()
+        .map(|(model, score, firsts, ranked)| {
+            json!({
+                "model": model,
+                "borda": score,
+                "first_place_votes": firsts,
+                "ranked_by": ranked,
+            })
+        })
+        .collect();
+
+    let pairwise_json: Value = pairwise
+        .iter()
+        .map(|(x, row)| {
+            let row_json: Value = row.iter().map(|(y, n)| (y.clone(), json!(n))).collect();
+            (x.clone(), row_json)
+        })
+        .collect();
+
+    Ok(json!({
+        "success": true,
+        "title": title,
+        "reviews_counted": reviews.len(),
+        "reviewers": reviews.iter().map(|r| r.engine.clone()).collect::<Vec<_>>(),
+        "consensus": models,
+        "scores": scores_json,
+        "pairwise": pairwise_json,
+        "condorcet_winner": condorcet_winner,
+    }))
+}
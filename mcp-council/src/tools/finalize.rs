@@ -1,357 +1,571 @@
 use anyhow::{Context, Result};
+use futures::stream::{FuturesUnordered, StreamExt};
 use serde_json::{json, Value};
 use std::fs;
-use std::path::{Path, PathBuf};
-use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::cli_runner;
-
-fn find_council_dir() -> Result<PathBuf> {
-    // Try current directory first
-    let current_dir = env::current_dir()?;
-    let council_in_current = current_dir.join(".council");
-    if council_in_current.exists() {
-        return Ok(council_in_current);
-    }
-
-    // Try parent directories (up to 5 levels)
-    let mut dir = current_dir.clone();
-    for _ in 0..5 {
-        let council_dir = dir.join(".council");
-        if council_dir.exists() {
-            return Ok(council_dir);
-        }
-        if let Some(parent) = dir.parent() {
-            dir = parent.to_path_buf();
-        } else {
-            break;
-        }
-    }
-
-    // Fallback: use current directory
-    Ok(current_dir.join(".council"))
-}
+use crate::context::{self, ContextLoader};
+use crate::error::CouncilError;
+use crate::scoring::{self, Ballot, Score};
+use crate::store::{format_response_content, resolve_store, CouncilStore, ReviewRecord};
 
 pub async fn handle_finalize(params: Value) -> Result<Value> {
     let title = params["title"]
         .as_str()
         .context("Missing required parameter: title")?;
-    let engine = params["engine"]
-        .as_str()
-        .unwrap_or("claude");
+    // `engine` may be a single name or a list; each runs its own chairman pass.
+    let engines: Vec<String> = match &params["engine"] {
+        Value::Array(arr) => arr
+            .iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect(),
+        Value::String(s) => vec![s.clone()],
+        _ => vec!["claude".to_string()],
+    };
+    let engines = if engines.is_empty() {
+        vec!["claude".to_string()]
+    } else {
+        engines
+    };
 
-    let council_base = find_council_dir()?;
-    let base_dir = council_base.join(title);
-    
-    if !base_dir.exists() {
-        return Err(anyhow::anyhow!(
-            "Directory not found: {} (searched from: {})",
-            base_dir.display(),
-            env::current_dir().unwrap_or_else(|_| PathBuf::from(".")).display()
-        ));
-    }
+    // Output format for the written artifacts. "either"/unspecified keeps the
+    // historical Markdown behavior.
+    let format = OutputFormat::from_param(params.get("format").and_then(|v| v.as_str()));
 
-    // Load Stage1 answers (markdown preferred, JSON for backward compatibility)
-    let answer_files: Vec<PathBuf> = fs::read_dir(&base_dir)
-        .context(format!("Failed to read directory: {}", base_dir.display()))?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let file_name = path.file_name()?.to_string_lossy();
-            
-            if file_name.contains("-answer.md") || file_name.ends_with("answer.md")
-                || file_name.contains("-answer.json") || file_name.ends_with("answer.json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
+    let store = resolve_store()?;
 
-    if answer_files.is_empty() {
-        return Err(anyhow::anyhow!(
-            "No Stage1 answer files found in {}",
-            base_dir.display()
-        ));
-    }
-
-    let mut stage1_results = Vec::new();
-    for file_path in &answer_files {
-        let parsed = read_stage1_answer(file_path)
-            .context(format!("Failed to parse answer file: {}", file_path.display()))?;
-        stage1_results.push(parsed);
-    }
-
-    // Load Stage2 reviews (markdown preferred, JSON for backward compatibility)
-    let review_files: Vec<PathBuf> = fs::read_dir(&base_dir)
-        .context(format!("Failed to read directory: {}", base_dir.display()))?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let file_name = path.file_name()?.to_string_lossy();
-            
-            if file_name.contains("peer-review") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
+    // Append-only event log so an external process can tail finalize progress.
+    // Only available when the backend is file-based.
+    let mut events = EventLog::open(store.base_path(title));
 
-    let mut stage2_results = Vec::new();
-    for file_path in &review_files {
-        let parsed = read_stage2_review(file_path)
-            .context(format!("Failed to parse review file: {}", file_path.display()))?;
-        stage2_results.push(parsed);
+    // Load Stage1 answers.
+    let answer_records = store.list_answers(title)?;
+    events.emit("answers_loaded", json!({ "count": answer_records.len() }));
+    if answer_records.is_empty() {
+        return Err(CouncilError::NoStage1Answers {
+            title: title.to_string(),
+        }
+        .into());
     }
 
-    if stage2_results.is_empty() {
+    // Load Stage2 reviews (with their machine-readable sidecars when present).
+    let review_records = store.list_reviews(title)?;
+    if review_records.is_empty() {
         return Err(anyhow::anyhow!(
             "No Stage2 review files found. Please run peer_review first."
         ));
     }
+    events.emit("reviews_loaded", json!({ "count": review_records.len() }));
 
     // Extract user query
-    let user_query = extract_user_query(&base_dir)?;
+    let user_query = store.read_query(title)?;
+
+    // Optionally load external reference material to ground the synthesis.
+    let context_sources: Vec<String> = params
+        .get("context")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+    let reference_material = if context_sources.is_empty() {
+        String::new()
+    } else {
+        let loaded = ContextLoader::from_params(&params).load(&context_sources).await;
+        context::reference_material(&loaded)
+    };
+
+    // Roster of council models, in a stable order.
+    let all_models: Vec<String> = answer_records
+        .iter()
+        .map(|r| {
+            r.content
+                .get("model")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown-model")
+                .to_string()
+        })
+        .collect();
+
+    // Aggregate peer rankings into numeric council scores.
+    let ballots: Vec<Ballot> = review_records
+        .iter()
+        .map(|record| build_ballot(record, &all_models))
+        .collect();
+    let scores = scoring::borda_scores(&ballots, &all_models);
+    events.emit("scores_computed", json!({ "scores": scoring::scoreboard_json(&scores) }));
 
     // Build Stage1 text
-    let stage1_text = stage1_results
+    let stage1_text = answer_records
         .iter()
         .enumerate()
-        .map(|(idx, result)| {
+        .map(|(idx, record)| {
             let default_model = format!("Model {}", idx + 1);
-            let model = result
+            let model = record
+                .content
                 .get("model")
                 .and_then(|v| v.as_str())
                 .unwrap_or(&default_model);
-            let response = format_response_content(result);
+            let response = format_response_content(&record.content);
             format!("Model: {}\nResponse: {}", model, response)
         })
         .collect::<Vec<_>>()
         .join("\n\n");
 
     // Build Stage2 text
-    let stage2_text = stage2_results
+    let stage2_text = review_records
         .iter()
-        .enumerate()
-        .map(|(idx, result)| {
-            let default_reviewer = format!("Reviewer {}", idx + 1);
-            let model = result
-                .get("engine")
-                .and_then(|v| v.as_str())
-                .unwrap_or(&default_reviewer);
-            let review = result
-                .get("review")
-                .and_then(|v| v.as_str())
-                .unwrap_or("No review content");
-            format!("Model: {}\nRanking: {}", model, review)
-        })
+        .map(|record| format!("Model: {}\nRanking: {}", record.engine, record.markdown))
         .collect::<Vec<_>>()
         .join("\n\n");
 
-    // Build chairman prompt
+    // Build chairman prompt. The scoreboard gives the Chairman a quantitative
+    // view of consensus alongside the prose reviews.
     let chairman_prompt = format!(
         r#"You are the Chairman of an LLM Council. Multiple AI models have provided responses to a user's question, and then ranked each other's responses.
 
 Original Question: {}
 
+{}
 STAGE 1 - Individual Responses:
 {}
 
 STAGE 2 - Peer Rankings:
 {}
 
+COUNCIL SCORES (Borda count, higher is better):
+{}
+
 Your task as Chairman is to synthesize all of this information into a single, comprehensive, accurate answer to the user's original question. Consider:
 - The individual responses and their insights
 - The peer rankings and what they reveal about response quality
+- The quantitative council scores above
 - Any patterns of agreement or disagreement
 
 Provide a clear, well-reasoned final answer that represents the council's collective wisdom:"#,
-        user_query, stage1_text, stage2_text
+        user_query,
+        reference_material,
+        stage1_text,
+        stage2_text,
+        scoring::scoreboard_text(&scores)
     );
 
-    // Run LLM CLI
-    let final_output = cli_runner::run_llm(engine, &chairman_prompt)
-        .await
-        .context("Failed to run LLM CLI for finalization")?;
+    events.emit("chairman_started", json!({ "engines": engines }));
+
+    // Fire every chairman engine concurrently. A failure is captured per-future
+    // so one engine dying doesn't abort the others.
+    let mut futures = FuturesUnordered::new();
+    for engine in &engines {
+        let engine = engine.clone();
+        let prompt = chairman_prompt.clone();
+        futures.push(async move {
+            let output = cli_runner::run_llm(&engine, &prompt).await;
+            (engine, output)
+        });
+    }
 
-    // Save markdown
-    let markdown = build_final_markdown(
-        title,
-        engine,
-        &user_query,
-        stage1_results.len(),
-        stage2_results.len(),
-        &final_output,
+    let mut engine_outputs: Vec<(String, String)> = Vec::new();
+    let mut engine_previews = serde_json::Map::new();
+    let mut engine_artifacts = serde_json::Map::new();
+    let mut errors = serde_json::Map::new();
+    while let Some((engine, output)) = futures.next().await {
+        match output {
+            Ok(final_output) => {
+                let artifact = build_artifact(
+                    format,
+                    title,
+                    &engine,
+                    &user_query,
+                    &answer_records,
+                    review_records.len(),
+                    &scores,
+                    &final_output,
+                );
+                let filename = format!("final-answer-by-{}.{}", engine, format.extension());
+                let location = store.write_final(title, &filename, &artifact)?;
+                eprintln!("✅ Saved final answer ({}) to: {}", format.extension(), location);
+                events.emit(
+                    "chairman_chunk",
+                    json!({ "engine": engine, "preview": preview_text(&final_output, 300) }),
+                );
+                engine_previews.insert(engine.clone(), json!(preview_text(&final_output, 300)));
+                engine_artifacts.insert(engine.clone(), json!(artifact));
+                engine_outputs.push((engine, final_output));
+            }
+            Err(e) => {
+                eprintln!("⚠️ Chairman engine '{}' failed: {}", engine, e);
+                errors.insert(engine, json!(e.to_string()));
+            }
+        }
+    }
+
+    if engine_outputs.is_empty() {
+        return Err(CouncilError::LlmCliFailed {
+            engine: engines.join(","),
+            reason: "all chairman engines failed".to_string(),
+        }
+        .into());
+    }
+
+    // Optional meta-synthesis: feed every chairman output into a final
+    // synthesis prompt run by a designated arbiter engine.
+    let mut consensus_preview = Value::Null;
+    if params.get("meta").and_then(|v| v.as_bool()).unwrap_or(false) {
+        let arbiter = params
+            .get("arbiter")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&engine_outputs[0].0)
+            .to_string();
+        let meta_prompt = build_meta_prompt(&user_query, &engine_outputs);
+        match cli_runner::run_llm(&arbiter, &meta_prompt).await {
+            Ok(consensus) => {
+                let artifact = build_artifact(
+                    format,
+                    title,
+                    &format!("consensus/{}", arbiter),
+                    &user_query,
+                    &answer_records,
+                    review_records.len(),
+                    &scores,
+                    &consensus,
+                );
+                let filename = format!("final-answer-consensus.{}", format.extension());
+                store.write_final(title, &filename, &artifact)?;
+                consensus_preview = json!(preview_text(&consensus, 300));
+            }
+            Err(e) => {
+                eprintln!("⚠️ Arbiter engine '{}' failed: {}", arbiter, e);
+                errors.insert(format!("arbiter:{}", arbiter), json!(e.to_string()));
+            }
+        }
+    }
+
+    events.emit(
+        "done",
+        json!({ "engines": engine_outputs.iter().map(|(e, _)| e.clone()).collect::<Vec<_>>() }),
     );
-    let final_md_path = base_dir.join(format!("final-answer-by-{}.md", engine));
-    fs::write(&final_md_path, &markdown)
-        .context(format!("Failed to write final markdown file: {} (current dir: {})", 
-            final_md_path.display(),
-            env::current_dir().unwrap_or_else(|_| PathBuf::from(".")).display()))?;
-    eprintln!("âœ… Saved final answer (markdown) to: {}", final_md_path.display());
 
     Ok(json!({
         "success": true,
-        "final_markdown_file": final_md_path.to_string_lossy(),
+        "engines": engine_outputs.iter().map(|(e, _)| e.clone()).collect::<Vec<_>>(),
         "summary": format!(
-            "Final answer generated using {} based on {} responses and {} reviews",
-            engine,
-            stage1_results.len(),
-            stage2_results.len()
+            "Final answer generated by {} engine(s) based on {} responses and {} reviews",
+            engine_outputs.len(),
+            answer_records.len(),
+            review_records.len()
         ),
-        "final_answer_preview": preview_text(&final_output, 300),
-        "markdown": markdown
+        "scores": scoring::scoreboard_json(&scores),
+        "format": format.extension(),
+        "previews": engine_previews,
+        "artifacts": engine_artifacts,
+        "consensus_preview": consensus_preview,
+        "errors": errors,
     }))
 }
 
+/// Append-only newline-delimited JSON event log for a finalize run, written to
+/// `.council/<title>/finalize-events.jsonl`. Each line carries a monotonically
+/// increasing sequence number and a millisecond timestamp and is flushed per
+/// event so an external process can tail it to drive a live UI. Logging is
+/// best-effort — if the file can't be opened, events are silently dropped and
+/// finalize proceeds.
+struct EventLog {
+    file: Option<File>,
+    seq: u64,
+}
+
+impl EventLog {
+    fn open(base_dir: Option<PathBuf>) -> Self {
+        let file = base_dir.and_then(|dir| {
+            fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(dir.join("finalize-events.jsonl"))
+                .ok()
+        });
+        Self { file, seq: 0 }
+    }
+
+    fn emit(&mut self, event: &str, data: Value) {
+        let Some(file) = self.file.as_mut() else {
+            return;
+        };
+        let ts = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let line = json!({
+            "seq": self.seq,
+            "ts": ts,
+            "event": event,
+            "data": data,
+        });
+        self.seq += 1;
+        if writeln!(file, "{}", line).and_then(|_| file.flush()).is_err() {
+            // Drop the handle so we don't keep retrying a broken writer.
+            self.file = None;
+        }
+    }
+}
+
+/// Build the meta-synthesis prompt fed to the arbiter engine, assembling every
+/// chairman's answer for a final reconciliation.
+fn build_meta_prompt(user_query: &str, outputs: &[(String, String)]) -> String {
+    let answers = outputs
+        .iter()
+        .map(|(engine, output)| format!("Chairman ({}):\n{}", engine, output))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    format!(
+        r#"You are the Arbiter of an LLM Council. Several Chairman engines have each produced a final answer to the user's question. Reconcile them into a single consensus answer, preferring points of agreement and resolving disagreements on the merits.
+
+Original Question: {}
+
+CHAIRMAN ANSWERS:
+{}
+
+Provide the consensus final answer:"#,
+        user_query, answers
+    )
+}
+
+/// Derive a reviewer's Borda ballot from its review record. Prefer the
+/// machine-readable sidecar (anonymized labels resolved to model names);
+/// otherwise parse model names out of the review markdown.
+fn build_ballot(record: &ReviewRecord, all_models: &[String]) -> Ballot {
+    let ranking = sidecar_ranking(record).unwrap_or_else(|| parse_ranking(&record.markdown, all_models));
+    // Best-effort link from reviewer engine to one of the council models.
+    let reviewer_model = all_models
+        .iter()
+        .find(|m| {
+            let m = m.to_ascii_lowercase();
+            let e = record.engine.to_ascii_lowercase();
+            m.contains(&e) || e.contains(&m)
+        })
+        .cloned();
+    Ballot {
+        reviewer: record.engine.clone(),
+        reviewer_model,
+        ranking,
+    }
+}
+
+/// Resolve a sidecar's anonymized ranking (`Response A`, …) back to model names.
+fn sidecar_ranking(record: &ReviewRecord) -> Option<Vec<String>> {
+    let sidecar = record.sidecar.as_ref()?;
+    let labels = sidecar.get("labels")?.as_object()?;
+    let ranking = sidecar.get("ranking")?.as_array()?;
+    let models: Vec<String> = ranking
+        .iter()
+        .filter_map(|l| l.as_str())
+        .filter_map(|l| labels.get(l).and_then(|m| m.as_str()))
+        .map(|m| m.to_string())
+        .collect();
+    if models.is_empty() {
+        None
+    } else {
+        Some(models)
+    }
+}
+
+/// Parse an ordered list of model names from the `FINAL RANKING:` block of a
+/// review, e.g. lines like `1. ModelX`. Only names in the council roster count.
+fn parse_ranking(review: &str, all_models: &[String]) -> Vec<String> {
+    let mut lines = review.lines();
+    for line in lines.by_ref() {
+        if line.trim().to_ascii_uppercase().starts_with("FINAL RANKING:") {
+            break;
+        }
+    }
+
+    let mut ranking = Vec::new();
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let label = match trimmed.find(['.', ')']) {
+            Some(pos) if trimmed[..pos].chars().all(|c| c.is_ascii_digit()) && pos > 0 => {
+                trimmed[pos + 1..].trim()
+            }
+            _ => trimmed,
+        };
+        if let Some(model) = all_models.iter().find(|m| label.contains(m.as_str())) {
+            ranking.push(model.clone());
+        }
+    }
+    ranking
+}
+
 fn build_final_markdown(
     title: &str,
     engine: &str,
     user_query: &str,
     stage1_count: usize,
     stage2_count: usize,
+    scores: &[Score],
     final_output: &str,
 ) -> String {
     format!(
-        "# Final Answer\n- title: {}\n- engine: {}\n- stage1 responses: {}\n- stage2 reviews: {}\n\n## User Question\n{}\n\n## Final Answer\n{}",
+        "# Final Answer\n- title: {}\n- engine: {}\n- stage1 responses: {}\n- stage2 reviews: {}\n\n## User Question\n{}\n\n{}\n\n## Final Answer\n{}",
         title,
         engine,
         stage1_count,
         stage2_count,
         user_query,
+        scoring::scoreboard_markdown(scores),
         final_output
     )
 }
 
-fn extract_user_query(base_dir: &Path) -> Result<String> {
-    // Try to find the original query in various possible locations
-    let possible_files = [
-        "query.txt",
-        "user_query.txt",
-        "question.txt",
-        "input.txt",
-    ];
-
-    for file_name in &possible_files {
-        let file_path = base_dir.join(file_name);
-        if file_path.exists() {
-            return Ok(fs::read_to_string(&file_path)?
-                .trim()
-                .to_string());
+/// Output format for the written finalize artifact.
+#[derive(Debug, Clone, Copy)]
+enum OutputFormat {
+    Markdown,
+    Json,
+    Html,
+}
+
+impl OutputFormat {
+    /// Parse the `format` param; `"either"`/unspecified defaults to Markdown.
+    fn from_param(value: Option<&str>) -> Self {
+        match value.map(|s| s.to_ascii_lowercase()).as_deref() {
+            Some("json") => OutputFormat::Json,
+            Some("html") => OutputFormat::Html,
+            _ => OutputFormat::Markdown,
         }
     }
 
-    // Try to extract from answer files
-    let answer_files: Vec<PathBuf> = fs::read_dir(base_dir)
-        .context("Failed to read directory")?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let file_name = path.file_name()?.to_string_lossy();
-            if file_name.contains("-answer.json") || file_name.ends_with("answer.json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    if let Some(first_file) = answer_files.first() {
-        let content = fs::read_to_string(first_file)?;
-        if let Ok(json_data) = serde_json::from_str::<Value>(&content) {
-            if let Some(query) = json_data.get("query").or(json_data.get("user_query")) {
-                if let Some(query_str) = query.as_str() {
-                    return Ok(query_str.to_string());
-                }
-            }
+    fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "md",
+            OutputFormat::Json => "json",
+            OutputFormat::Html => "html",
         }
     }
-
-    Ok("Unknown query".to_string())
 }
 
-fn read_stage1_answer(path: &Path) -> Result<Value> {
-    let content = fs::read_to_string(path)
-        .context(format!("Failed to read file: {}", path.display()))?;
-
-    let model_from_name = path.file_stem()
-        .and_then(|s| s.to_str())
-        .map(|s| s.replace("-answer", ""))
-        .unwrap_or_else(|| "unknown-model".to_string());
-
-    if let Ok(json_data) = serde_json::from_str::<Value>(&content) {
-        let model = json_data.get("model")
-            .and_then(|v| v.as_str())
-            .unwrap_or(&model_from_name)
-            .to_string();
-        let response = format_response_content(&json_data);
-        return Ok(json!({
-            "model": model,
-            "response": response,
-            "raw": json_data
-        }));
+/// Serialize the finalized answer in the requested format.
+#[allow(clippy::too_many_arguments)]
+fn build_artifact(
+    format: OutputFormat,
+    title: &str,
+    engine: &str,
+    user_query: &str,
+    answers: &[crate::store::AnswerRecord],
+    stage2_count: usize,
+    scores: &[Score],
+    final_output: &str,
+) -> String {
+    let markdown = build_final_markdown(
+        title,
+        engine,
+        user_query,
+        answers.len(),
+        stage2_count,
+        scores,
+        final_output,
+    );
+    match format {
+        OutputFormat::Markdown => markdown,
+        OutputFormat::Html => markdown_to_html(title, &markdown),
+        OutputFormat::Json => {
+            let responses: Vec<Value> = answers
+                .iter()
+                .map(|record| {
+                    json!({
+                        "model": record.content.get("model").and_then(|v| v.as_str()).unwrap_or("unknown-model"),
+                        "response": format_response_content(&record.content),
+                    })
+                })
+                .collect();
+            let document = json!({
+                "title": title,
+                "engine": engine,
+                "query": user_query,
+                "responses": responses,
+                "scores": scoring::scoreboard_json(scores),
+                "chairman_answer": final_output,
+            });
+            serde_json::to_string_pretty(&document).unwrap_or_else(|_| "{}".to_string())
+        }
     }
-
-    Ok(json!({
-        "model": model_from_name,
-        "response": content,
-        "raw": content
-    }))
 }
 
-fn read_stage2_review(path: &Path) -> Result<Value> {
-    let content = fs::read_to_string(path)
-        .context(format!("Failed to read file: {}", path.display()))?;
-
-    let engine_from_name = path.file_stem()
-        .and_then(|s| s.to_str())
-        .map(|s| s.replace("peer-review-by-", ""))
-        .unwrap_or_else(|| "unknown-engine".to_string());
-
-    if let Ok(json_data) = serde_json::from_str::<Value>(&content) {
-        let engine = json_data.get("engine")
-            .and_then(|v| v.as_str())
-            .unwrap_or(&engine_from_name)
-            .to_string();
-        let review = json_data.get("review")
-            .and_then(|v| v.as_str().map(|s| s.to_string()))
-            .unwrap_or_else(|| format_response_content(&json_data));
-        return Ok(json!({
-            "engine": engine,
-            "review": review,
-            "raw": json_data
-        }));
-    }
-
-    Ok(json!({
-        "engine": engine_from_name,
-        "review": content,
-        "raw": content
-    }))
-}
+/// Render the final-answer Markdown to a minimal standalone HTML document.
+/// Handles headings, the scores table, and paragraphs — enough for dashboards
+/// and static sites to embed without a separate conversion step.
+fn markdown_to_html(title: &str, markdown: &str) -> String {
+    let escape = |s: &str| {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    };
+
+    let mut body = String::new();
+    let mut in_table = false;
+    let mut table_header_done = false;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        let is_table_row = trimmed.starts_with('|');
+        if in_table && !is_table_row {
+            body.push_str("</table>\n");
+            in_table = false;
+            table_header_done = false;
+        }
 
-fn format_response_content(content: &Value) -> String {
-    // Try to extract the actual response text from various possible JSON structures
-    if let Some(text) = content.get("response").and_then(|v| v.as_str()) {
-        return text.to_string();
-    }
-    if let Some(text) = content.get("content").and_then(|v| v.as_str()) {
-        return text.to_string();
+        if let Some(rest) = trimmed.strip_prefix("### ") {
+            body.push_str(&format!("<h3>{}</h3>\n", escape(rest)));
+        } else if let Some(rest) = trimmed.strip_prefix("## ") {
+            body.push_str(&format!("<h2>{}</h2>\n", escape(rest)));
+        } else if let Some(rest) = trimmed.strip_prefix("# ") {
+            body.push_str(&format!("<h1>{}</h1>\n", escape(rest)));
+        } else if is_table_row {
+            // Skip the `|---|---|` separator row.
+            if trimmed.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ')) {
+                continue;
+            }
+            if !in_table {
+                body.push_str("<table>\n");
+                in_table = true;
+            }
+            let cells: Vec<&str> = trimmed.trim_matches('|').split('|').map(|c| c.trim()).collect();
+            let tag = if table_header_done { "td" } else { "th" };
+            table_header_done = true;
+            let row: String = cells
+                .iter()
+                .map(|c| format!("<{tag}>{}</{tag}>", escape(c)))
+                .collect();
+            body.push_str(&format!("<tr>{}</tr>\n", row));
+        } else if trimmed.is_empty() {
+            body.push('\n');
+        } else {
+            body.push_str(&format!("<p>{}</p>\n", escape(trimmed)));
+        }
     }
-    if let Some(text) = content.as_str() {
-        return text.to_string();
+    if in_table {
+        body.push_str("</table>\n");
     }
-    
-    // Fallback: pretty print the JSON
-    serde_json::to_string_pretty(content).unwrap_or_else(|_| "Invalid content".to_string())
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        body
+    )
 }
 
 fn preview_text(text: &str, max_len: usize) -> String {
     if text.len() <= max_len {
         text.to_string()
     } else {
-        format!("{}...", &text[..max_len])
+        // Walk back to a char boundary so multibyte UTF-8 (emoji, accents) in
+        // LLM output doesn't panic the slice.
+        let mut end = max_len;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &text[..end])
     }
 }
-
@@ -1,36 +1,9 @@
 use anyhow::{Context, Result};
 use serde_json::{json, Value};
-use std::env;
-use std::fs;
-use std::path::{Path, PathBuf};
 
 use crate::cli_runner;
-
-fn find_council_dir() -> Result<PathBuf> {
-    // Try current directory first
-    let current_dir = env::current_dir()?;
-    let council_in_current = current_dir.join(".council");
-    if council_in_current.exists() {
-        return Ok(council_in_current);
-    }
-
-    // Try parent directories (up to 5 levels)
-    let mut dir = current_dir.clone();
-    for _ in 0..5 {
-        let council_dir = dir.join(".council");
-        if council_dir.exists() {
-            return Ok(council_dir);
-        }
-        if let Some(parent) = dir.parent() {
-            dir = parent.to_path_buf();
-        } else {
-            break;
-        }
-    }
-
-    // Fallback: use current directory
-    Ok(current_dir.join(".council"))
-}
+use crate::error::CouncilError;
+use crate::store::{format_response_content, resolve_store, CouncilStore};
 
 pub async fn handle_peer_review(params: Value) -> Result<Value> {
     let title = params["title"]
@@ -61,49 +34,21 @@ pub async fn handle_peer_review(params: Value) -> Result<Value> {
     };
     let self_model = params.get("self_model").and_then(|v| v.as_str());
 
-    let council_base = find_council_dir()?;
-    let base_dir = council_base.join(title);
-    
-    if !base_dir.exists() {
-        return Err(anyhow::anyhow!(
-            "Directory not found: {} (searched from: {})",
-            base_dir.display(),
-            env::current_dir().unwrap_or_else(|_| PathBuf::from(".")).display()
-        ));
-    }
-
-    // Find all Stage1 answer files (markdown preferred, JSON for backward compatibility)
-    let answer_files: Vec<PathBuf> = fs::read_dir(&base_dir)
-        .context(format!("Failed to read directory: {}", base_dir.display()))?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let file_name = path.file_name()?.to_string_lossy();
-            
-            if file_name.contains("-answer.md") || file_name.ends_with("answer.md")
-                || file_name.contains("-answer.json") || file_name.ends_with("answer.json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
+    let store = resolve_store()?;
 
-    if answer_files.is_empty() {
-        return Err(anyhow::anyhow!(
-            "No Stage1 answer files found in {}",
-            base_dir.display()
-        ));
+    let answer_records = store.list_answers(title)?;
+    if answer_records.is_empty() {
+        return Err(CouncilError::NoStage1Answers {
+            title: title.to_string(),
+        }
+        .into());
     }
 
-    // Load and parse all answer files, optionally excluding self_model
+    // Load all answers, optionally excluding self_model.
     let mut answers = Vec::new();
-    let mut labels = Vec::new();
-    for file_path in answer_files.iter() {
-        let content_value = read_stage1_answer(file_path)
-            .context(format!("Failed to parse answer file: {}", file_path.display()))?;
-
-        let model_name = content_value
+    for record in answer_records {
+        let model_name = record
+            .content
             .get("model")
             .and_then(|v| v.as_str())
             .unwrap_or("unknown-model");
@@ -119,27 +64,27 @@ pub async fn handle_peer_review(params: Value) -> Result<Value> {
         }
 
         answers.push(json!({
-            "file": file_path.file_name().unwrap().to_string_lossy(),
-            "content": content_value
+            "file": record.name,
+            "content": record.content
         }));
     }
 
     if answers.is_empty() {
-        return Err(anyhow::anyhow!(
-            "No Stage1 answers available after applying self_model exclusion"
-        ));
+        return Err(CouncilError::SelfExclusionEmptied {
+            self_model: self_model.unwrap_or_default().to_string(),
+        }
+        .into());
     }
 
     // Re-label responses after exclusion to keep labels consecutive
     for (idx, answer) in answers.iter_mut().enumerate() {
         let label = format!("Response {}", char::from(b'A' + idx as u8));
-        labels.push(label.clone());
         answer["label"] = json!(label);
     }
 
     // Build review prompt
-    let user_query = extract_user_query(&base_dir)?;
-    
+    let user_query = store.read_query(title)?;
+
     let responses_text = answers
         .iter()
         .map(|a| {
@@ -189,59 +134,77 @@ Now provide your evaluation and ranking:"#,
     // Run LLM CLI
     let review_output = cli_runner::run_llm(engine, &ranking_prompt)
         .await
-        .context("Failed to run LLM CLI for peer review")?;
-
-    // Save markdown
+        .map_err(|e| CouncilError::LlmCliFailed {
+            engine: engine.to_string(),
+            reason: e.to_string(),
+        })?;
+
+    // Build the markdown artifact and a machine-readable sidecar so
+    // `council.aggregate` can resolve anonymized rankings back to model names.
+    // The sidecar records the structured ranking parsed from the `FINAL
+    // RANKING:` block plus the label→model map used to anonymize responses.
     let markdown = build_review_markdown(title, engine, &user_query, answers.len(), &review_output);
-    let review_md_path = base_dir.join(format!("peer-review-by-{}.md", engine_for_file));
-    let legacy_review_md = base_dir.join("peer-review.md");
-    if legacy_review_md.exists() && !review_md_path.exists() {
-        // Migrate old file name to engine-suffixed variant if present
-        fs::rename(&legacy_review_md, &review_md_path).or_else(|_| {
-            let legacy_content = fs::read_to_string(&legacy_review_md)?;
-            fs::write(&review_md_path, legacy_content)
-        }).ok();
-    }
-    // Migrate legacy pattern: peer-review-<engine>.md -> peer-review-by-<engine>.md
-    for entry in fs::read_dir(&base_dir)? {
-        if let Ok(dir_entry) = entry {
-            let path = dir_entry.path();
-            if let Some(file_name) = path.file_name().and_then(|f| f.to_str()) {
-                if file_name.starts_with("peer-review-")
-                    && file_name.ends_with(".md")
-                    && !file_name.contains("peer-review-by-")
-                {
-                    let engine_part = file_name
-                        .trim_start_matches("peer-review-")
-                        .trim_end_matches(".md");
-                    if !engine_part.is_empty() {
-                        let new_path = base_dir.join(format!("peer-review-by-{}.md", engine_part));
-                        if !new_path.exists() {
-                            fs::rename(&path, &new_path).or_else(|_| {
-                                let legacy_content = fs::read_to_string(&path)?;
-                                fs::write(&new_path, legacy_content)
-                            }).ok();
-                        }
-                    }
-                }
-            }
+    let ranking = parse_final_ranking(&review_output);
+    let mut label_models = serde_json::Map::new();
+    for answer in &answers {
+        if let (Some(label), Some(model)) = (
+            answer["label"].as_str(),
+            answer["content"].get("model").and_then(|v| v.as_str()),
+        ) {
+            label_models.insert(label.to_string(), json!(model));
         }
     }
-    fs::write(&review_md_path, &markdown)
-        .context(format!("Failed to write review markdown file: {} (current dir: {})",
-            review_md_path.display(),
-            env::current_dir().unwrap_or_else(|_| PathBuf::from(".")).display()))?;
-    eprintln!("✅ Saved peer review (markdown) to: {}", review_md_path.display());
+    let sidecar = json!({
+        "title": title,
+        "engine": engine,
+        "labels": label_models,
+        "ranking": ranking,
+    });
+
+    let review_location = store.write_review(title, &engine_for_file, &markdown, &sidecar)?;
+    eprintln!("✅ Saved peer review to: {}", review_location);
 
     Ok(json!({
         "success": true,
-        "review_markdown_file": review_md_path.to_string_lossy(),
+        "review_markdown_file": review_location,
         "summary": format!("Peer review completed for {} answers using {}", answers.len(), engine),
         "review_preview": preview_text(&review_output, 200),
+        "ranking": ranking,
         "markdown": markdown
     }))
 }
 
+/// Extract the ordered list of response labels from a review's `FINAL RANKING:`
+/// block. Lines are expected to be `<n>. Response X`; anything after the marker
+/// that doesn't match is ignored. Returns labels best-to-worst.
+pub(crate) fn parse_final_ranking(review_output: &str) -> Vec<String> {
+    let mut lines = review_output.lines();
+    for line in lines.by_ref() {
+        if line.trim().to_ascii_uppercase().starts_with("FINAL RANKING:") {
+            break;
+        }
+    }
+
+    let mut ranking = Vec::new();
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        // Strip a leading `<n>.` / `<n>)` ordinal if present.
+        let label = match trimmed.find(['.', ')']) {
+            Some(pos) if trimmed[..pos].chars().all(|c| c.is_ascii_digit()) && pos > 0 => {
+                trimmed[pos + 1..].trim()
+            }
+            _ => trimmed,
+        };
+        if label.starts_with("Response ") {
+            ranking.push(label.to_string());
+        }
+    }
+    ranking
+}
+
 fn build_review_markdown(title: &str, engine: &str, user_query: &str, answer_count: usize, review_output: &str) -> String {
     format!(
         "# Peer Review\n- title: {}\n- engine: {}\n- answers reviewed: {}\n\n## User Question\n{}\n\n## Review\n{}",
@@ -253,104 +216,16 @@ fn build_review_markdown(title: &str, engine: &str, user_query: &str, answer_cou
     )
 }
 
-fn extract_user_query(base_dir: &Path) -> Result<String> {
-    // Try to find the original query in various possible locations
-    let possible_files = [
-        "query.txt",
-        "user_query.txt",
-        "question.txt",
-        "input.txt",
-    ];
-
-    for file_name in &possible_files {
-        let file_path = base_dir.join(file_name);
-        if file_path.exists() {
-            return Ok(fs::read_to_string(&file_path)?
-                .trim()
-                .to_string());
-        }
-    }
-
-    // Try to extract from answer files
-    let answer_files: Vec<PathBuf> = fs::read_dir(base_dir)
-        .context("Failed to read directory")?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let file_name = path.file_name()?.to_string_lossy();
-            if file_name.contains("-answer.json") || file_name.ends_with("answer.json") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    if let Some(first_file) = answer_files.first() {
-        let content = fs::read_to_string(first_file)?;
-        if let Ok(json_data) = serde_json::from_str::<Value>(&content) {
-            if let Some(query) = json_data.get("query").or(json_data.get("user_query")) {
-                if let Some(query_str) = query.as_str() {
-                    return Ok(query_str.to_string());
-                }
-            }
-        }
-    }
-
-    Ok("Unknown query".to_string())
-}
-
-fn read_stage1_answer(path: &Path) -> Result<Value> {
-    let content = fs::read_to_string(path)
-        .context(format!("Failed to read file: {}", path.display()))?;
-
-    let model_from_name = path.file_stem()
-        .and_then(|s| s.to_str())
-        .map(|s| s.replace("-answer", ""))
-        .unwrap_or_else(|| "unknown-model".to_string());
-
-    if let Ok(json_data) = serde_json::from_str::<Value>(&content) {
-        let model = json_data.get("model")
-            .and_then(|v| v.as_str())
-            .unwrap_or(&model_from_name)
-            .to_string();
-        let response = format_response_content(&json_data);
-        return Ok(json!({
-            "model": model,
-            "response": response,
-            "raw": json_data
-        }));
-    }
-
-    // Treat as markdown/plain text
-    Ok(json!({
-        "model": model_from_name,
-        "response": content,
-        "raw": content
-    }))
-}
-
-fn format_response_content(content: &Value) -> String {
-    // Try to extract the actual response text from various possible JSON structures
-    if let Some(text) = content.get("response").and_then(|v| v.as_str()) {
-        return text.to_string();
-    }
-    if let Some(text) = content.get("content").and_then(|v| v.as_str()) {
-        return text.to_string();
-    }
-    if let Some(text) = content.as_str() {
-        return text.to_string();
-    }
-    
-    // Fallback: pretty print the JSON
-    serde_json::to_string_pretty(content).unwrap_or_else(|_| "Invalid content".to_string())
-}
-
 fn preview_text(text: &str, max_len: usize) -> String {
     if text.len() <= max_len {
         text.to_string()
     } else {
-        format!("{}...", &text[..max_len])
+        // Walk back to a char boundary so multibyte UTF-8 (emoji, accents) in
+        // LLM output doesn't panic the slice.
+        let mut end = max_len;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &text[..end])
     }
 }
-
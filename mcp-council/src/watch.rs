@@ -0,0 +1,275 @@
+//! `--watch` mode: re-run peer review when new Stage1 answers land.
+//!
+//! The watcher resolves a title's `.council/<title>` directory through the same
+//! [`FilesystemStore::discover`] search the tools use, so it keeps working when
+//! invoked from a subdirectory. Filesystem events are debounced so a burst of
+//! writes from parallel model runs coalesces into a single trigger, and review
+//! (optionally finalize) only fires once a configurable quorum of answers is
+//! present. Results are emitted as MCP notifications on stdout.
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde_json::{json, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, SystemTime};
+
+use crate::store::{CouncilStore, FilesystemStore};
+
+/// Options controlling the answer watcher.
+pub struct WatchConfig {
+    pub title: String,
+    pub engine: String,
+    pub self_model: Option<String>,
+    /// Minimum number of Stage1 answers before review is triggered.
+    pub quorum: usize,
+    /// Also run `council.finalize` after each successful review.
+    pub finalize: bool,
+    /// Window over which rapid filesystem events are coalesced.
+    pub debounce: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            engine: "claude".to_string(),
+            self_model: None,
+            quorum: 2,
+            finalize: false,
+            debounce: Duration::from_millis(500),
+        }
+    }
+}
+
+pub async fn run_watch(config: WatchConfig) -> Result<()> {
+    let store = FilesystemStore::discover()?;
+    let watch_dir = store.root().join(&config.title);
+    if !watch_dir.exists() {
+        return Err(anyhow::anyhow!(
+            "Cannot watch missing directory: {}",
+            watch_dir.display()
+        ));
+    }
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            // Forward any create/modify/remove in the directory; whether the
+            // answer set changed is checked after the debounce window.
+            if matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                let _ = tx.send(());
+            }
+        }
+    })
+    .context("Failed to create filesystem watcher")?;
+    watcher
+        .watch(&watch_dir, RecursiveMode::NonRecursive)
+        .context(format!("Failed to watch {}", watch_dir.display()))?;
+
+    eprintln!(
+        "👀 Watching {} (quorum {}, engine {})",
+        watch_dir.display(),
+        config.quorum,
+        config.engine
+    );
+
+    // Track the set of answer filenames last processed so we only re-run when
+    // the inputs actually change.
+    let mut last_seen: BTreeSet<String> = BTreeSet::new();
+
+    while rx.recv().await.is_some() {
+        // Debounce: keep draining events until the channel is quiet for a full
+        // debounce window.
+        loop {
+            match tokio::time::timeout(config.debounce, rx.recv()).await {
+                Ok(Some(())) => continue,
+                _ => break,
+            }
+        }
+
+        let answers = match store.list_answers(&config.title) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("watch: failed to list answers: {}", e);
+                continue;
+            }
+        };
+        let current: BTreeSet<String> = answers.iter().map(|a| a.name.clone()).collect();
+
+        if current.len() < config.quorum {
+            eprintln!(
+                "watch: {}/{} answers, waiting for quorum",
+                current.len(),
+                config.quorum
+            );
+            continue;
+        }
+        if current == last_seen {
+            continue; // No change in the answer set.
+        }
+        last_seen = current;
+
+        trigger_review(&config).await;
+    }
+
+    Ok(())
+}
+
+/// Run peer review (and optionally finalize) for the watched title, emitting
+/// each result as an MCP notification.
+async fn trigger_review(config: &WatchConfig) {
+    let mut args = json!({ "title": config.title, "engine": config.engine });
+    if let Some(self_model) = &config.self_model {
+        args["self_model"] = json!(self_model);
+    }
+
+    match crate::tools::peer_review::handle_peer_review(args).await {
+        Ok(result) => emit_notification("council/peer_review.completed", result),
+        Err(e) => {
+            emit_notification(
+                "council/peer_review.failed",
+                json!({ "title": config.title, "error": e.to_string() }),
+            );
+            return;
+        }
+    }
+
+    if config.finalize {
+        let args = json!({ "title": config.title, "engine": config.engine });
+        match crate::tools::finalize::handle_finalize(args).await {
+            Ok(result) => emit_notification("council/finalize.completed", result),
+            Err(e) => emit_notification(
+                "council/finalize.failed",
+                json!({ "title": config.title, "error": e.to_string() }),
+            ),
+        }
+    }
+}
+
+/// Options controlling the finalize watcher.
+pub struct FinalizeWatchConfig {
+    pub title: String,
+    pub engine: String,
+    /// Window over which rapid filesystem events are coalesced.
+    pub debounce: Duration,
+}
+
+impl Default for FinalizeWatchConfig {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            engine: "claude".to_string(),
+            debounce: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Watch a title's `.council/<title>` directory and re-run `council.finalize`
+/// whenever the set of Stage1 answers or peer reviews changes. A burst of
+/// writes from parallel runs coalesces into a single finalize via the debounce
+/// window, and finalize is skipped when the input fingerprint (sorted
+/// filenames + mtimes) is unchanged.
+pub async fn run_finalize_watch(config: FinalizeWatchConfig) -> Result<()> {
+    let store = FilesystemStore::discover()?;
+    let watch_dir = store.root().join(&config.title);
+    if !watch_dir.exists() {
+        return Err(anyhow::anyhow!(
+            "Cannot watch missing directory: {}",
+            watch_dir.display()
+        ));
+    }
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            if matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                let _ = tx.send(());
+            }
+        }
+    })
+    .context("Failed to create filesystem watcher")?;
+    watcher
+        .watch(&watch_dir, RecursiveMode::NonRecursive)
+        .context(format!("Failed to watch {}", watch_dir.display()))?;
+
+    eprintln!("👀 Watching {} for finalize inputs", watch_dir.display());
+
+    let mut last_fingerprint = input_fingerprint(&watch_dir);
+
+    while rx.recv().await.is_some() {
+        loop {
+            match tokio::time::timeout(config.debounce, rx.recv()).await {
+                Ok(Some(())) => continue,
+                _ => break,
+            }
+        }
+
+        let fingerprint = input_fingerprint(&watch_dir);
+        if fingerprint == last_fingerprint {
+            continue; // Inputs unchanged since the last finalize.
+        }
+        last_fingerprint = fingerprint;
+
+        let args = json!({ "title": config.title, "engine": config.engine });
+        match crate::tools::finalize::handle_finalize(args).await {
+            Ok(result) => emit_notification("council/finalize.completed", result),
+            Err(e) => emit_notification(
+                "council/finalize.failed",
+                json!({ "title": config.title, "error": e.to_string() }),
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Hash the sorted (filename, mtime) pairs of the finalize inputs — Stage1
+/// answers and peer reviews — so an unchanged input set can be skipped.
+fn input_fingerprint(dir: &std::path::Path) -> u64 {
+    let mut entries: Vec<(String, Option<SystemTime>)> = Vec::new();
+    if let Ok(read_dir) = std::fs::read_dir(dir) {
+        for entry in read_dir.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.contains("-answer.md")
+                || name.contains("-answer.json")
+                || name.ends_with("answer.md")
+                || name.ends_with("answer.json")
+                || name.contains("peer-review")
+            {
+                let mtime = entry.metadata().and_then(|m| m.modified()).ok();
+                entries.push((name, mtime));
+            }
+        }
+    }
+    entries.sort();
+
+    let mut hasher = DefaultHasher::new();
+    for (name, mtime) in &entries {
+        name.hash(&mut hasher);
+        if let Some(mtime) = mtime {
+            if let Ok(dur) = mtime.duration_since(SystemTime::UNIX_EPOCH) {
+                dur.as_nanos().hash(&mut hasher);
+            }
+        }
+    }
+    hasher.finish()
+}
+
+/// Print a JSON-RPC notification (no id) to stdout so a connected client can
+/// observe watch-driven progress.
+fn emit_notification(method: &str, params: Value) {
+    let notification = json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+    });
+    println!("{}", notification);
+}